@@ -1,13 +1,38 @@
-use std::ffi::CString;
+use std::ffi::{CStr, CString};
 use std::os::raw::c_char;
+
+use turbopath::AbsoluteSystemPathBuf;
+use turborepo_repository::global_hash::compute_global_hash;
+
+/// Breaking change from the old zero-arg signature; out-of-tree Go/JS
+/// callers must be updated to pass the repo root in the same release.
+///
+/// # Safety
+/// `repo_root` must be null or a valid, NUL-terminated UTF-8 C string.
+///
+/// Returns null on failure (bad/missing repo root, unreadable inputs) —
+/// callers must check for null rather than treat `""` as a valid hash.
+/// Only pass a non-null return value to `deallocate_global_hash`.
 #[no_mangle]
-pub extern "C" fn get_global_hash() -> *mut c_char {
-  let val = CString::new("foo").unwrap();
-  val.into_raw()
+pub unsafe extern "C" fn get_global_hash(repo_root: *const c_char) -> *mut c_char {
+  if repo_root.is_null() {
+    return std::ptr::null_mut();
+  }
+  let repo_root = CStr::from_ptr(repo_root).to_string_lossy();
+  let hash = AbsoluteSystemPathBuf::from_cwd(repo_root.as_ref())
+    .map_err(|e| e.to_string())
+    .and_then(|repo_root| compute_global_hash(&repo_root).map_err(|e| e.to_string()));
+  match hash {
+    Ok(hash) => CString::new(hash).unwrap().into_raw(),
+    Err(_) => std::ptr::null_mut(),
+  }
 }
 
 #[no_mangle]
 pub unsafe extern "C" fn deallocate_global_hash(ptr: *mut c_char) {
+  if ptr.is_null() {
+    return;
+  }
   drop(CString::from_raw(ptr))
 }
 
@@ -0,0 +1,49 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use twox_hash::XxHash64;
+
+/// A key paired with its hash, computed once at construction. `Hash` just
+/// writes the stored value instead of rehashing the key, so a `PreHashedMap`
+/// never rehashes a key it holds, and a reused `PreHashed` query is never
+/// rehashed either.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PreHashed<K> {
+    hash: u64,
+    key: K,
+}
+
+impl<K: std::hash::Hash> PreHashed<K> {
+    pub fn new(key: K) -> Self {
+        let mut hasher = XxHash64::default();
+        key.hash(&mut hasher);
+        Self {
+            hash: std::hash::Hasher::finish(&hasher),
+            key,
+        }
+    }
+}
+
+impl<K> std::hash::Hash for PreHashed<K> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        state.write_u64(self.hash);
+    }
+}
+
+impl<K: PartialEq> PartialEq for PreHashed<K> {
+    fn eq(&self, other: &Self) -> bool {
+        self.hash == other.hash && self.key == other.key
+    }
+}
+
+impl<K: Eq> Eq for PreHashed<K> {}
+
+impl<K: Default + std::hash::Hash> Default for PreHashed<K> {
+    fn default() -> Self {
+        Self::new(K::default())
+    }
+}
+
+/// Map keyed by [`PreHashed`] rather than `K` directly, so repeated lookups
+/// on the same key (e.g. a `Transition` layer name) reuse its stored hash.
+pub type PreHashedMap<K, V> = HashMap<PreHashed<K>, V>;
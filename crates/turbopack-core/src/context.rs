@@ -0,0 +1,71 @@
+use anyhow::Result;
+use turbo_tasks::Vc;
+
+use crate::module::Module;
+
+/// One alternative a `ProcessResult` can resolve to.
+#[turbo_tasks::value]
+#[derive(Clone)]
+pub enum ProcessResultAlternative {
+    Module(Vc<Box<dyn Module>>),
+    External,
+    Ignore,
+}
+
+/// The result of processing a source, as a list of alternatives rather than
+/// a single outcome, so e.g. a transition can offer a primary module
+/// alongside an external fallback.
+#[turbo_tasks::value]
+pub struct ProcessResult {
+    pub alternatives: Vec<ProcessResultAlternative>,
+}
+
+impl ProcessResult {
+    pub fn new(alternatives: Vec<ProcessResultAlternative>) -> Self {
+        Self { alternatives }
+    }
+
+    /// Constructor for call sites that only ever produce one `Module`.
+    pub fn module(module: Vc<Box<dyn Module>>) -> Self {
+        Self::new(vec![ProcessResultAlternative::Module(module)])
+    }
+}
+
+impl Vc<ProcessResult> {
+    /// The first `Module` alternative, if any.
+    pub async fn first_module(self) -> Result<Option<Vc<Box<dyn Module>>>> {
+        let this = self.await?;
+        Ok(this.alternatives.iter().find_map(|a| match a {
+            ProcessResultAlternative::Module(m) => Some(*m),
+            _ => None,
+        }))
+    }
+
+    /// Applies `map` to every `Module` alternative; `External`/`Ignore`
+    /// alternatives pass through untouched. Plain Rust, not a
+    /// `#[turbo_tasks::function]` — `map` is a closure, not a value
+    /// `turbo_tasks` can serialize as a function argument.
+    pub async fn map_module(
+        self,
+        map: impl Fn(Vc<Box<dyn Module>>) -> Vc<Box<dyn Module>>,
+    ) -> Result<Vc<ProcessResult>> {
+        let this = self.await?;
+        let alternatives = this
+            .alternatives
+            .iter()
+            .map(|a| match a {
+                ProcessResultAlternative::Module(m) => ProcessResultAlternative::Module(map(*m)),
+                ProcessResultAlternative::External => ProcessResultAlternative::External,
+                ProcessResultAlternative::Ignore => ProcessResultAlternative::Ignore,
+            })
+            .collect();
+        Ok(ProcessResult::new(alternatives).cell())
+    }
+
+    /// Concatenates this result's alternatives with `other`'s.
+    pub async fn merge_alternatives(self, other: Vc<ProcessResult>) -> Result<Vc<ProcessResult>> {
+        let mut alternatives = self.await?.alternatives.clone();
+        alternatives.extend(other.await?.alternatives.iter().cloned());
+        Ok(ProcessResult::new(alternatives).cell())
+    }
+}
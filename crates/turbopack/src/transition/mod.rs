@@ -1,10 +1,9 @@
 pub(crate) mod context_transition;
 
-use std::collections::HashMap;
-
 use anyhow::Result;
 pub use context_transition::ContextTransition;
-use turbo_tasks::{Value, ValueDefault, Vc};
+use turbo_tasks::{RcStr, Value, ValueDefault, Vc};
+use turbo_tasks_hash::PreHashedMap;
 use turbopack_core::{
     compile_time_info::CompileTimeInfo, context::ProcessResult, module::Module,
     reference_type::ReferenceType, source::Source,
@@ -30,7 +29,7 @@ pub trait Transition {
         compile_time_info
     }
     /// Apply modifications to the layer
-    fn process_layer(self: Vc<Self>, layer: Vc<String>) -> Vc<String>;
+    fn process_layer(self: Vc<Self>, layer: Vc<RcStr>) -> Vc<RcStr>;
     /// Apply modifications/wrapping to the module options context
     fn process_module_options_context(
         self: Vc<Self>,
@@ -65,6 +64,7 @@ pub trait Transition {
             self.process_module_options_context(module_asset_context.module_options_context);
         let resolve_options_context =
             self.process_resolve_options_context(module_asset_context.resolve_options_context);
+        // layer is Vc<RcStr> now, same as ModuleAssetContext::layer
         let layer = self.process_layer(module_asset_context.layer);
         let module_asset_context = ModuleAssetContext::new(
             module_asset_context.transitions,
@@ -84,19 +84,19 @@ pub trait Transition {
     ) -> Result<Vc<ProcessResult>> {
         let asset = self.process_source(asset);
         let module_asset_context = self.process_context(module_asset_context);
-        let m = module_asset_context.process_default(asset, reference_type);
-        Ok(match *m.await? {
-            ProcessResult::Module(m) => {
-                ProcessResult::Module(self.process_module(m, module_asset_context))
-            }
-            ProcessResult::Ignore => ProcessResult::Ignore,
-        }
-        .cell())
+        let result = module_asset_context.process_default(asset, reference_type);
+        // process_module only applies to Module alternatives; External/Ignore pass through
+        result
+            .map_module(|module| self.process_module(module, module_asset_context))
+            .await
     }
 }
 
+/// The transitions that are available for a given context. Keys are
+/// pre-hashed, so a stored layer name is never rehashed; a lookup hashes its
+/// query once, when its `PreHashed` is built.
 #[turbo_tasks::value(transparent)]
-pub struct TransitionsByName(HashMap<String, Vc<Box<dyn Transition>>>);
+pub struct TransitionsByName(PreHashedMap<RcStr, Vc<Box<dyn Transition>>>);
 
 #[turbo_tasks::value_impl]
 impl ValueDefault for TransitionsByName {
@@ -2,6 +2,7 @@ use anyhow::{anyhow, Result};
 use napi_derive::napi;
 use turbopath::AbsoluteSystemPathBuf;
 use turborepo_repository::{
+    global_hash::compute_global_hash,
     inference::{RepoMode, RepoState},
     package_manager::{self, PackageManager as RustPackageManager},
 };
@@ -60,4 +61,9 @@ impl Repository {
             Err(ref e) => Err(anyhow!("{}", e)),
         }
     }
+
+    #[napi]
+    pub fn global_hash(&self) -> Result<String> {
+        compute_global_hash(&self.repo_state.root).map_err(|e| anyhow!("{}", e))
+    }
 }
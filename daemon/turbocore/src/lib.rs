@@ -1,18 +1,41 @@
 // mod turbo_grpc;
 // use turbo_grpc::Turbo;
-use anyhow::Result;
+use std::ffi::CStr;
+use std::os::raw::c_char;
+use std::pin::Pin;
+
+use anyhow::{anyhow, Result};
+use notify::{RecursiveMode, Watcher};
+use tokio::sync::watch;
+use tokio_stream::{wrappers::WatchStream, Stream, StreamExt};
 use tonic::{transport::Server, Request, Response, Status};
+use turbopath::AbsoluteSystemPathBuf;
+use turborepo_repository::global_hash::compute_global_hash;
 
 use turbo::turbo_server::{Turbo, TurboServer};
-use turbo::{GlobalHashReply, GlobalHashRequest};
+use turbo::{GlobalHashReply, GlobalHashRequest, GlobalHashUpdate};
 
 pub mod turbo {
     tonic::include_proto!("daemon");
 }
 
+/// Current global hash + version; version bumps whenever the hash changes.
+#[derive(Clone, Default)]
+struct GlobalHashState {
+    version: u64,
+    hash: Vec<u8>,
+}
+
+/// # Safety
+/// `repo_root` must be a valid, NUL-terminated UTF-8 C string.
 #[no_mangle]
-pub extern "C" fn run() -> i32 {
-    match run_server() {
+pub unsafe extern "C" fn run(repo_root: *const c_char) -> i32 {
+    if repo_root.is_null() {
+        println!("got error: repo_root is null");
+        return 1;
+    }
+    let repo_root = CStr::from_ptr(repo_root).to_string_lossy().into_owned();
+    match run_server(repo_root) {
       Err(e) => {
         println!("got error {:?}", e);
         1
@@ -21,14 +44,28 @@ pub extern "C" fn run() -> i32 {
     }
 }
 
-pub fn run_server() -> Result<()> {
+pub fn run_server(repo_root: String) -> Result<()> {
   let rt = tokio::runtime::Builder::new_current_thread()
         .enable_all()
         .build()?;
 
   let addr = "127.0.0.1:5555".parse()?;
   rt.block_on(async move {
-    let turbod = Turbod {};
+    let repo_root = AbsoluteSystemPathBuf::from_cwd(&repo_root)
+      .map_err(|e| anyhow!("couldn't resolve repo root {}: {}", repo_root, e))?;
+    // don't fail to boot over a bad initial hash (unreadable turbo.json,
+    // transient FS error) — start empty and let the watcher fill it in
+    // (unlike the FFI get_global_hash, which returns null on failure)
+    let hash = compute_global_hash(&repo_root).unwrap_or_else(|e| {
+      println!("failed to compute initial global hash: {:?}", e);
+      String::new()
+    });
+    let (global_hash, _) = watch::channel(GlobalHashState {
+      version: 0,
+      hash: hash.into_bytes(),
+    });
+    watch_global_hash(repo_root, global_hash.clone())?;
+    let turbod = Turbod { global_hash };
     let result = Server::builder().add_service(TurboServer::new(turbod)).serve(addr).await;
     match result {
       Ok(_) => Ok(()),
@@ -41,20 +78,110 @@ pub fn run_server() -> Result<()> {
   //Ok(())
 }
 
-struct Turbod {}
+// Directories that are never global-hash inputs; recursing into them risks
+// blowing past the OS's watch-count limit (e.g. inotify's
+// `max_user_watches`) on large monorepos for no benefit.
+const WATCH_IGNORE_DIRS: &[&str] = &["node_modules", ".git", ".next", ".turbo", "dist", "build"];
+
+/// Adds a non-recursive watch on `dir` and recurses into its subdirectories,
+/// skipping `WATCH_IGNORE_DIRS` at every level (not just directly under
+/// `dir`), so e.g. `packages/foo/node_modules` is skipped the same as a
+/// top-level `node_modules`.
+fn watch_dir_ignoring(watcher: &mut notify::RecommendedWatcher, dir: &std::path::Path) -> Result<()> {
+  watcher.watch(dir, RecursiveMode::NonRecursive)?;
+  for entry in std::fs::read_dir(dir)? {
+    let entry = entry?;
+    if !entry.file_type()?.is_dir() {
+      continue;
+    }
+    if WATCH_IGNORE_DIRS.iter().any(|ignored| entry.file_name() == *ignored) {
+      println!("global hash watcher: not watching {:?}", entry.path());
+      continue;
+    }
+    watch_dir_ignoring(watcher, &entry.path())?;
+  }
+  Ok(())
+}
+
+/// Watches `repo_root` for changes to the global hash's inputs (lockfile,
+/// turbo.json, nested globalDependencies, ...) and recomputes/`send()`s a
+/// new `GlobalHashState` when the hash actually changes, bumping `version`.
+/// The `Watcher` is moved into the spawned task so it keeps running for as
+/// long as the server does.
+fn watch_global_hash(
+  repo_root: AbsoluteSystemPathBuf,
+  sender: watch::Sender<GlobalHashState>,
+) -> Result<()> {
+  let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+  let mut watcher = notify::recommended_watcher(move |event: notify::Result<notify::Event>| {
+    let _ = tx.send(event);
+  })?;
+  // Walk the tree ourselves instead of a blind RecursiveMode::Recursive, so
+  // ignored dirs (node_modules, ...) are skipped at every depth, not just
+  // under repo_root — packages/*/node_modules is just as unwatchable as
+  // node_modules itself.
+  watch_dir_ignoring(&mut watcher, repo_root.as_path())?;
+
+  tokio::spawn(async move {
+    // Keep the watcher alive for the lifetime of this task.
+    let _watcher = watcher;
+    while let Some(event) = rx.recv().await {
+      if event.is_err() {
+        continue;
+      }
+      // coalesce a burst of events (e.g. `npm install`) into one recompute
+      while rx.try_recv().is_ok() {}
+      let Ok(hash) = compute_global_hash(&repo_root) else {
+        continue;
+      };
+      let hash = hash.into_bytes();
+      sender.send_if_modified(|state| {
+        if state.hash != hash {
+          state.version += 1;
+          state.hash = hash.clone();
+          true
+        } else {
+          false
+        }
+      });
+    }
+  });
+
+  Ok(())
+}
+
+struct Turbod {
+    global_hash: watch::Sender<GlobalHashState>,
+}
 
 #[tonic::async_trait]
 impl Turbo for Turbod {
     async fn get_global_hash(
         &self,
-        req: Request<GlobalHashRequest>,
+        _req: Request<GlobalHashRequest>,
     ) -> Result<Response<GlobalHashReply>, Status> {
-        let f = "foo!";
         let reply = GlobalHashReply {
-            hash: f.as_bytes().to_vec().clone(),
+            hash: self.global_hash.borrow().hash.clone(),
         };
         Ok(Response::new(reply))
     }
+
+    type SubscribeGlobalHashStream =
+        Pin<Box<dyn Stream<Item = Result<GlobalHashUpdate, Status>> + Send>>;
+
+    async fn subscribe_global_hash(
+        &self,
+        _req: Request<GlobalHashRequest>,
+    ) -> Result<Response<Self::SubscribeGlobalHashStream>, Status> {
+        // WatchStream yields the current value immediately, then updates
+        let stream = WatchStream::new(self.global_hash.subscribe()).map(|state| {
+            Ok(GlobalHashUpdate {
+                version: state.version,
+                hash: state.hash,
+            })
+        });
+        Ok(Response::new(Box::pin(stream)))
+    }
 }
 
 #[cfg(test)]